@@ -0,0 +1,16 @@
+//! Minimal glob matching for folder names, supporting `*` wildcards. Enough
+//! for patterns like `*.git` or `node_modules`; not a full glob grammar.
+
+pub(crate) fn glob_match(glob: &str, name: &str) -> bool {
+    fn inner(glob: &[u8], name: &[u8]) -> bool {
+        match (glob.first(), name.first()) {
+            (None, None) => true,
+            (Some(b'*'), _) => {
+                inner(&glob[1..], name) || (!name.is_empty() && inner(glob, &name[1..]))
+            }
+            (Some(g), Some(n)) if g == n => inner(&glob[1..], &name[1..]),
+            _ => false,
+        }
+    }
+    inner(glob.as_bytes(), name.as_bytes())
+}
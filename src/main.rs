@@ -1,16 +1,25 @@
+mod glob;
+mod message;
+mod nerd_font;
+mod profile_store;
+mod theme;
+
 use std::path::{Path, PathBuf};
 
-use anyhow::{Context, Result, bail};
+use anyhow::{bail, Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
 use indicatif::{ProgressBar, ProgressStyle};
 
 use folco_core::{
     color::FolderColor,
     progress::{progress_channel, Progress},
-    CustomizationContextBuilder, CustomizationProfile, DecalSettings,
-    OverlaySettings, SerializablePosition, SerializableSvgSource,
+    CustomizationContextBuilder, CustomizationProfile, DecalSettings, OverlaySettings,
+    SerializablePosition, SerializableSvgSource,
 };
 
+use message::{MessageKind, MessageOptions};
+use theme::Theme;
+
 #[derive(Parser)]
 #[command(name = "folco")]
 #[command(author, version, about, long_about = None)]
@@ -19,17 +28,52 @@ struct Cli {
     #[arg(long, short, global = true)]
     verbose: bool,
 
+    /// Output format: a human-readable progress bar, or newline-delimited
+    /// JSON progress events on stdout
+    #[arg(long, global = true, value_enum, default_value = "human")]
+    format: OutputFormat,
+
+    /// Use plain ASCII tags like "[ok]"/"[fail]" instead of emoji
+    #[arg(long, global = true)]
+    no_emoji: bool,
+
+    /// Disable colored output
+    #[arg(long, global = true)]
+    no_color: bool,
+
+    /// Suppress all output except the final summary and hard errors. Applies
+    /// to human output only; with --format json every event is still printed
+    #[arg(long, global = true)]
+    quiet: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
 
+impl Cli {
+    fn messages(&self) -> MessageOptions {
+        MessageOptions {
+            no_emoji: self.no_emoji,
+            no_color: self.no_color,
+            quiet: self.quiet,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Default)]
+enum OutputFormat {
+    #[default]
+    Human,
+    Json,
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Customize folder icons with a profile
     #[command(group(
         clap::ArgGroup::new("customization")
             .required(true)
-            .args(["profile", "color", "decal", "overlay"])
+            .args(["profile", "profile_name", "theme", "color", "decal", "overlay"])
             .multiple(true)
     ))]
     Customize {
@@ -41,6 +85,16 @@ enum Commands {
         #[arg(long, value_name = "JSON")]
         profile: Option<String>,
 
+        /// Load a profile saved with `folco profile save` (alternative to
+        /// --profile and the individual options)
+        #[arg(long, value_name = "NAME")]
+        profile_name: Option<String>,
+
+        /// TOML theme file mapping folder names to profiles (alternative to
+        /// --profile and the individual options)
+        #[arg(long, value_name = "FILE")]
+        theme: Option<PathBuf>,
+
         // === HSL Mutation Options ===
         /// Folder color
         #[arg(long, value_enum, value_name = "COLOR")]
@@ -69,6 +123,9 @@ enum Commands {
         /// Overlay scale factor (0.0-1.0)
         #[arg(long, value_name = "SCALE", default_value = "0.70")]
         overlay_scale: f32,
+
+        #[command(flatten)]
+        walk: WalkArgs,
     },
 
     /// Reset folder icons to system default
@@ -76,14 +133,270 @@ enum Commands {
         /// Directories to reset
         #[arg(required = true)]
         directories: Vec<PathBuf>,
+
+        #[command(flatten)]
+        walk: WalkArgs,
+    },
+
+    /// Manage a library of named, reusable customization profiles
+    Profile {
+        #[command(subcommand)]
+        action: ProfileCommand,
+    },
+
+    /// Render a folder icon to an image file without touching any directory
+    #[command(group(
+        clap::ArgGroup::new("preview_customization")
+            .required(true)
+            .args(["profile", "profile_name", "color", "decal", "overlay"])
+            .multiple(true)
+    ))]
+    Preview {
+        /// JSON-serialized CustomizationProfile (alternative to individual options)
+        #[arg(long, value_name = "JSON")]
+        profile: Option<String>,
+
+        /// Load a profile saved with `folco profile save` (alternative to
+        /// --profile and the individual options)
+        #[arg(long, value_name = "NAME")]
+        profile_name: Option<String>,
+
+        // === HSL Mutation Options ===
+        /// Folder color
+        #[arg(long, value_enum, value_name = "COLOR")]
+        color: Option<FolderColor>,
+
+        // === Decal Options ===
+        /// Decal source: an SVG file path, raw SVG markup, or a Nerd Font
+        /// glyph (name or literal PUA codepoint)
+        #[arg(long, value_name = "SOURCE")]
+        decal: Option<String>,
+
+        /// Decal scale factor (0.0-1.0)
+        #[arg(long, value_name = "SCALE", default_value = "0.70")]
+        decal_scale: f32,
+
+        // === Overlay Options ===
+        /// Overlay source: an SVG file path, raw SVG markup, emoji character,
+        /// emoji name, or one of a small set of Nerd Font glyph names (nf-...)
+        #[arg(long, value_name = "SOURCE")]
+        overlay: Option<String>,
+
+        /// Overlay position
+        #[arg(long, value_name = "POSITION", default_value = "center")]
+        overlay_position: PositionArg,
+
+        /// Overlay scale factor (0.0-1.0)
+        #[arg(long, value_name = "SCALE", default_value = "0.70")]
+        overlay_scale: f32,
+
+        /// Where to write the rendered icon. Written as SVG if the
+        /// extension is ".svg", otherwise as PNG.
+        #[arg(long, value_name = "FILE", default_value = "preview.png")]
+        output: PathBuf,
+
+        /// Rendered icon size in pixels
+        #[arg(long, value_name = "PIXELS", default_value = "512")]
+        size: u32,
     },
 
     /// Print the JSON Schema for CustomizationProfile
     Schema,
 }
 
+#[derive(Subcommand)]
+enum ProfileCommand {
+    /// Build a profile from flags and save it under a name for reuse
+    Save {
+        /// Name to save the profile under
+        name: String,
+
+        // === HSL Mutation Options ===
+        /// Folder color
+        #[arg(long, value_enum, value_name = "COLOR")]
+        color: Option<FolderColor>,
+
+        // === Decal Options ===
+        /// Decal source: an SVG file path, raw SVG markup, or a Nerd Font
+        /// glyph (name or literal PUA codepoint)
+        #[arg(long, value_name = "SOURCE")]
+        decal: Option<String>,
+
+        /// Decal scale factor (0.0-1.0)
+        #[arg(long, value_name = "SCALE", default_value = "0.70")]
+        decal_scale: f32,
+
+        // === Overlay Options ===
+        /// Overlay source: an SVG file path, raw SVG markup, emoji character,
+        /// emoji name, or one of a small set of Nerd Font glyph names (nf-...)
+        #[arg(long, value_name = "SOURCE")]
+        overlay: Option<String>,
+
+        /// Overlay position
+        #[arg(long, value_name = "POSITION", default_value = "center")]
+        overlay_position: PositionArg,
+
+        /// Overlay scale factor (0.0-1.0)
+        #[arg(long, value_name = "SCALE", default_value = "0.70")]
+        overlay_scale: f32,
+    },
+
+    /// List saved profile names
+    List,
+
+    /// Print the JSON for a saved profile
+    Show {
+        /// Name of the saved profile
+        name: String,
+    },
+
+    /// Delete a saved profile
+    Rm {
+        /// Name of the saved profile
+        name: String,
+    },
+}
+
+/// Options shared by `Customize` and `Reset` for expanding a root directory
+/// into the tree of folders it should actually apply to.
+#[derive(clap::Args)]
+struct WalkArgs {
+    /// Recurse into subdirectories, applying the profile to every folder
+    /// matching --include/--exclude instead of just the given directories
+    #[arg(long)]
+    recursive: bool,
+
+    /// Maximum recursion depth (only meaningful with --recursive)
+    #[arg(long, value_name = "N")]
+    depth: Option<usize>,
+
+    /// Only include folders whose path (relative to the given directory)
+    /// matches this glob; can be repeated
+    #[arg(long = "include", value_name = "GLOB")]
+    include: Vec<String>,
+
+    /// Exclude folders whose path (relative to the given directory) matches
+    /// this glob; can be repeated
+    #[arg(long = "exclude", value_name = "GLOB")]
+    exclude: Vec<String>,
+}
+
+/// Expand each root directory into the list of folders it should apply to,
+/// per `walk`'s --recursive/--depth/--include/--exclude options. Walks
+/// breadth-first and skips symlinked directories to avoid cycles.
+fn expand_directories(directories: Vec<PathBuf>, walk: &WalkArgs) -> Result<Vec<PathBuf>> {
+    if !walk.recursive {
+        return Ok(directories);
+    }
+
+    let mut expanded = Vec::new();
+    for root in directories {
+        for (path, _depth) in walk_dir_tree(&root, walk.depth)? {
+            let relative = path
+                .strip_prefix(&root)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .to_string();
+            let included = walk.include.is_empty()
+                || walk.include.iter().any(|g| glob::glob_match(g, &relative));
+            let excluded = walk.exclude.iter().any(|g| glob::glob_match(g, &relative));
+            if included && !excluded {
+                expanded.push(path);
+            }
+        }
+    }
+
+    Ok(expanded)
+}
+
+/// Build a `CustomizationProfile` from the individual `--color`/`--decal`/
+/// `--overlay` flags shared by `Commands::Customize` and
+/// `ProfileCommand::Save`.
+fn build_profile_from_flags(
+    color: Option<FolderColor>,
+    decal: Option<String>,
+    decal_scale: f32,
+    overlay: Option<String>,
+    overlay_position: PositionArg,
+    overlay_scale: f32,
+) -> Result<CustomizationProfile> {
+    let mut p = CustomizationProfile::new();
+
+    // HSL mutation from --color preset
+    if let Some(color) = color {
+        p = p.with_hsl_mutation(color.to_hsl_mutation_settings());
+    }
+
+    // Decal
+    if let Some(ref source) = decal {
+        p = p.with_decal(DecalSettings {
+            source: resolve_decal_source(source)?,
+            scale: decal_scale,
+            enabled: true,
+        });
+    }
+
+    // Overlay
+    if let Some(ref source) = overlay {
+        let source = resolve_overlay_source(source)?;
+        p = p.with_overlay(OverlaySettings {
+            source,
+            position: overlay_position.into(),
+            scale: overlay_scale,
+            enabled: true,
+        });
+    }
+
+    Ok(p)
+}
+
+/// Resolve a `CustomizationProfile` the way `Commands::Customize` and
+/// `Commands::Preview` both do: from inline JSON, a saved profile name, or
+/// the individual `--color`/`--decal`/`--overlay` flags, in that order of
+/// precedence.
+#[allow(clippy::too_many_arguments)]
+fn resolve_customize_profile(
+    profile: Option<String>,
+    profile_name: Option<String>,
+    color: Option<FolderColor>,
+    decal: Option<String>,
+    decal_scale: f32,
+    overlay: Option<String>,
+    overlay_position: PositionArg,
+    overlay_scale: f32,
+) -> Result<CustomizationProfile> {
+    if let Some(json) = profile {
+        return CustomizationProfile::from_json(&json)
+            .context("Failed to parse CustomizationProfile JSON");
+    }
+
+    if let Some(name) = profile_name {
+        return profile_store::load(&name);
+    }
+
+    build_profile_from_flags(
+        color,
+        decal,
+        decal_scale,
+        overlay,
+        overlay_position,
+        overlay_scale,
+    )
+}
+
+/// Resolve a decal source string: an SVG file path, raw SVG markup, or a
+/// Nerd Font glyph (name or literal PUA codepoint).
+pub(crate) fn resolve_decal_source(input: &str) -> Result<SerializableSvgSource> {
+    if let Some(source) = nerd_font::resolve_nerd_font_source(input.trim())? {
+        return Ok(source);
+    }
+
+    let svg = resolve_svg_source(input)?;
+    Ok(SerializableSvgSource::from_svg(svg))
+}
+
 /// Resolve an SVG source string (for decals â€” only SVG file paths and raw markup).
-fn resolve_svg_source(input: &str) -> Result<String> {
+pub(crate) fn resolve_svg_source(input: &str) -> Result<String> {
     let trimmed = input.trim();
 
     // Raw SVG markup
@@ -122,7 +435,7 @@ fn looks_like_emoji(s: &str) -> bool {
 }
 
 /// Resolve an overlay source string (SVG, emoji character, emoji name, or file path).
-fn resolve_overlay_source(input: &str) -> Result<SerializableSvgSource> {
+pub(crate) fn resolve_overlay_source(input: &str) -> Result<SerializableSvgSource> {
     let trimmed = input.trim();
 
     // Raw SVG markup
@@ -132,7 +445,11 @@ fn resolve_overlay_source(input: &str) -> Result<SerializableSvgSource> {
 
     // File path (must exist on disk and have an SVG-like extension)
     let path = Path::new(trimmed);
-    if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("svg")) && path.exists() {
+    if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"))
+        && path.exists()
+    {
         let svg = std::fs::read_to_string(path)
             .with_context(|| format!("Failed to read overlay SVG file: {}", path.display()))?;
         return Ok(SerializableSvgSource::from_svg(svg));
@@ -143,6 +460,11 @@ fn resolve_overlay_source(input: &str) -> Result<SerializableSvgSource> {
         return Ok(SerializableSvgSource::from_emoji(trimmed));
     }
 
+    // Nerd Font glyph name or literal PUA codepoint
+    if let Some(source) = nerd_font::resolve_nerd_font_source(trimmed)? {
+        return Ok(source);
+    }
+
     // Fallback: treat as an emoji name (e.g. "duck", "star", "heart")
     Ok(SerializableSvgSource::from_emoji_name(trimmed))
 }
@@ -179,8 +501,6 @@ fn create_progress_bar(total: u64) -> ProgressBar {
     pb
 }
 
-
-
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
@@ -189,55 +509,115 @@ async fn main() -> Result<()> {
         Commands::Customize {
             directories,
             profile,
+            profile_name,
+            theme,
             color,
             decal,
             decal_scale,
             overlay,
             overlay_position,
             overlay_scale,
+            walk,
         } => {
-            let profile = if let Some(json) = profile {
-                // Parse JSON profile
-                CustomizationProfile::from_json(&json)
-                    .context("Failed to parse CustomizationProfile JSON")?
-            } else {
-                // Build profile from individual options
-                let mut p = CustomizationProfile::new();
-
-                // HSL mutation from --color preset
-                if let Some(color) = color {
-                    p = p.with_hsl_mutation(color.to_hsl_mutation_settings());
-                }
-
-                // Decal
-                if let Some(ref source) = decal {
-                    let svg = resolve_svg_source(source)?;
-                    p = p.with_decal(DecalSettings {
-                        source: SerializableSvgSource::from_svg(svg),
-                        scale: decal_scale,
-                        enabled: true,
-                    });
-                }
+            if let Some(theme_path) = theme {
+                let theme = Theme::load(&theme_path)?;
+                let directories = expand_directories(directories, &walk)?;
+                return customize_folders_themed(
+                    directories,
+                    theme,
+                    cli.verbose,
+                    cli.format,
+                    cli.messages(),
+                )
+                .await;
+            }
 
-                // Overlay
-                if let Some(ref source) = overlay {
-                    let source = resolve_overlay_source(source)?;
-                    p = p.with_overlay(OverlaySettings {
-                        source,
-                        position: overlay_position.into(),
-                        scale: overlay_scale,
-                        enabled: true,
-                    });
-                }
+            let profile = resolve_customize_profile(
+                profile,
+                profile_name,
+                color,
+                decal,
+                decal_scale,
+                overlay,
+                overlay_position,
+                overlay_scale,
+            )?;
 
-                p
-            };
+            let directories = expand_directories(directories, &walk)?;
+            customize_folders(
+                directories,
+                profile,
+                cli.verbose,
+                cli.format,
+                cli.messages(),
+            )
+            .await?;
+        }
 
-            customize_folders(directories, profile, cli.verbose).await?;
+        Commands::Reset { directories, walk } => {
+            let directories = expand_directories(directories, &walk)?;
+            reset_folders(directories, cli.verbose, cli.format, cli.messages()).await?;
         }
 
-        Commands::Reset { directories } => {
-            reset_folders(directories, cli.verbose).await?;
+        Commands::Profile { action } => match action {
+            ProfileCommand::Save {
+                name,
+                color,
+                decal,
+                decal_scale,
+                overlay,
+                overlay_position,
+                overlay_scale,
+            } => {
+                let profile = build_profile_from_flags(
+                    color,
+                    decal,
+                    decal_scale,
+                    overlay,
+                    overlay_position,
+                    overlay_scale,
+                )?;
+                profile_store::save(&name, &profile)?;
+                println!("Saved profile {:?}", name);
+            }
+            ProfileCommand::List => {
+                for name in profile_store::list()? {
+                    println!("{name}");
+                }
+            }
+            ProfileCommand::Show { name } => {
+                println!("{}", profile_store::show(&name)?);
+            }
+            ProfileCommand::Rm { name } => {
+                profile_store::remove(&name)?;
+                println!("Removed profile {:?}", name);
+            }
+        },
+
+        Commands::Preview {
+            profile,
+            profile_name,
+            color,
+            decal,
+            decal_scale,
+            overlay,
+            overlay_position,
+            overlay_scale,
+            output,
+            size,
+        } => {
+            let profile = resolve_customize_profile(
+                profile,
+                profile_name,
+                color,
+                decal,
+                decal_scale,
+                overlay,
+                overlay_position,
+                overlay_scale,
+            )?;
+
+            render_preview(profile, size, output)?;
         }
 
         Commands::Schema => {
@@ -250,8 +630,228 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-async fn customize_folders(directories: Vec<PathBuf>, profile: CustomizationProfile, verbose: bool) -> Result<()> {
-    println!("Initializing...");
+/// Walk a directory tree breadth-first, returning every directory under
+/// (and including) `root`, paired with its depth (`root` is depth 0).
+/// Symlinked directories are skipped to avoid cycles. `max_depth` of `None`
+/// means unlimited. A directory that can't be read (e.g. a permission
+/// error partway through a large `--recursive` walk) is logged to stderr
+/// and skipped rather than aborting the whole walk.
+fn walk_dir_tree(root: &Path, max_depth: Option<usize>) -> Result<Vec<(PathBuf, usize)>> {
+    let mut found = vec![(root.to_path_buf(), 0)];
+    let mut queue = std::collections::VecDeque::from([(root.to_path_buf(), 0usize)]);
+
+    while let Some((dir, depth)) = queue.pop_front() {
+        if max_depth.is_some_and(|max| depth >= max) {
+            continue;
+        }
+
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(error) => {
+                eprintln!("Skipping unreadable directory {}: {error}", dir.display());
+                continue;
+            }
+        };
+        for entry in entries {
+            let entry = entry?;
+            let path = entry.path();
+            let is_symlink = entry.file_type()?.is_symlink();
+            if is_symlink || !path.is_dir() {
+                continue;
+            }
+            found.push((path.clone(), depth + 1));
+            queue.push_back((path, depth + 1));
+        }
+    }
+
+    Ok(found)
+}
+
+/// Customize a tree of folders according to a theme, building one
+/// `CustomizationProfile` per matched directory instead of a single global
+/// profile. `directories` is expected to already be expanded per
+/// `--recursive`/`--depth`/`--include`/`--exclude` (see `expand_directories`),
+/// same as `customize_folders`.
+async fn customize_folders_themed(
+    directories: Vec<PathBuf>,
+    theme: Theme,
+    verbose: bool,
+    format: OutputFormat,
+    messages: MessageOptions,
+) -> Result<()> {
+    if format == OutputFormat::Human {
+        messages.print(MessageKind::Info, "Initializing...");
+    }
+
+    let mut ctx = CustomizationContextBuilder::new()
+        .build()
+        .context("Failed to initialize customization context")?;
+
+    let mut targets = Vec::new();
+    for path in directories {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string());
+        if let Some(profile) = theme.resolve(&name)? {
+            targets.push((path, profile));
+        }
+    }
+
+    let total = targets.len() as u64;
+    let pb = (format == OutputFormat::Human && !messages.quiet).then(|| create_progress_bar(total));
+    if let Some(pb) = &pb {
+        pb.set_message("Applying theme...");
+    }
+
+    let mut succeeded = 0u64;
+    let mut failed = 0u64;
+    let mut index = 0u64;
+
+    for (path, profile) in targets {
+        let (tx, mut rx) = progress_channel(4);
+        let handle = tokio::spawn(async move {
+            let mut events = Vec::new();
+            while let Some(progress) = rx.recv().await {
+                events.push(progress);
+            }
+            events
+        });
+
+        ctx.customize_folders_async(vec![path.clone()], &profile, tx)
+            .await;
+
+        let events = handle.await?;
+
+        if format == OutputFormat::Json {
+            for event in &events {
+                if matches!(
+                    event,
+                    Progress::FolderComplete { .. } | Progress::FolderFailed { .. }
+                ) {
+                    index += 1;
+                }
+                print_json_event(event, index, total);
+            }
+        }
+
+        let outcome = events.iter().find_map(|event| match event {
+            Progress::FolderFailed { error, .. } => Some(Err(error.to_string())),
+            Progress::FolderComplete { .. } => Some(Ok(())),
+            _ => None,
+        });
+
+        match outcome {
+            Some(Err(error)) => {
+                failed += 1;
+                if format == OutputFormat::Human {
+                    let text = if verbose {
+                        format!("Failed {}: {}", path.display(), error)
+                    } else {
+                        format!("Failed {}", path.display())
+                    };
+                    match &pb {
+                        Some(pb) => pb.suspend(|| messages.eprint(MessageKind::Failure, &text)),
+                        None => messages.eprint(MessageKind::Failure, &text),
+                    }
+                }
+            }
+            Some(Ok(())) => succeeded += 1,
+            None => {}
+        }
+        if let Some(pb) = &pb {
+            pb.inc(1);
+        }
+    }
+
+    match format {
+        OutputFormat::Human => {
+            if let Some(pb) = &pb {
+                pb.finish_and_clear();
+            }
+            println!("Completed: {} succeeded, {} failed", succeeded, failed);
+        }
+        OutputFormat::Json => {
+            print_json_event(&Progress::Completed { succeeded, failed }, index, total);
+        }
+    }
+
+    Ok(())
+}
+
+/// Print a single `Progress` event as a newline-delimited JSON object, for
+/// `--format json`. `index`/`total` track how many folders have completed
+/// (succeeded or failed) so far, since `Progress` itself doesn't carry them.
+fn print_json_event(progress: &Progress, index: u64, total: u64) {
+    let event = match progress {
+        Progress::Started { total } => serde_json::json!({"event": "started", "total": total}),
+        Progress::Rendering => serde_json::json!({"event": "rendering"}),
+        Progress::RenderFailed { error } => {
+            serde_json::json!({"event": "render_failed", "error": error.to_string()})
+        }
+        Progress::Processing { path, .. } => {
+            serde_json::json!({"event": "processing", "path": path.display().to_string()})
+        }
+        Progress::FolderComplete { path, .. } => serde_json::json!({
+            "event": "folder_complete",
+            "path": path.display().to_string(),
+            "index": index,
+            "total": total,
+        }),
+        Progress::FolderFailed { path, error, .. } => serde_json::json!({
+            "event": "folder_failed",
+            "path": path.display().to_string(),
+            "index": index,
+            "total": total,
+            "error": error.to_string(),
+        }),
+        Progress::Completed { succeeded, failed } => serde_json::json!({
+            "event": "completed",
+            "succeeded": succeeded,
+            "failed": failed,
+        }),
+    };
+    println!("{event}");
+}
+
+/// Render a profile through the same rendering pipeline `customize_folders`
+/// uses, and write the result to `output` instead of applying it to any
+/// directory. Written as SVG if `output`'s extension is ".svg", otherwise PNG.
+fn render_preview(profile: CustomizationProfile, size: u32, output: PathBuf) -> Result<()> {
+    let ctx = CustomizationContextBuilder::new()
+        .build()
+        .context("Failed to initialize customization context")?;
+
+    let icon = ctx
+        .render_icon(&profile, size)
+        .context("Failed to render icon")?;
+
+    if output
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"))
+    {
+        std::fs::write(&output, icon.svg())
+            .with_context(|| format!("Failed to write preview: {}", output.display()))?;
+    } else {
+        std::fs::write(&output, icon.png_bytes())
+            .with_context(|| format!("Failed to write preview: {}", output.display()))?;
+    }
+
+    println!("Wrote preview to {}", output.display());
+
+    Ok(())
+}
+
+async fn customize_folders(
+    directories: Vec<PathBuf>,
+    profile: CustomizationProfile,
+    verbose: bool,
+    format: OutputFormat,
+    messages: MessageOptions,
+) -> Result<()> {
+    if format == OutputFormat::Human {
+        messages.print(MessageKind::Info, "Initializing...");
+    }
 
     let mut ctx = CustomizationContextBuilder::new()
         .build()
@@ -260,57 +860,91 @@ async fn customize_folders(directories: Vec<PathBuf>, profile: CustomizationProf
     let (tx, mut rx) = progress_channel(32);
 
     let total = directories.len() as u64;
-    let pb = create_progress_bar(total);
 
     // Spawn progress handler
-    let progress_handle = tokio::spawn(async move {
-        while let Some(progress) = rx.recv().await {
-            match progress {
-                Progress::Started { total } => {
-                    pb.set_length(total as u64);
-                    pb.set_message("Starting...");
-                }
-                Progress::Rendering => {
-                    pb.set_message("Rendering icons...");
-                }
-                Progress::RenderFailed { error } => {
-                    pb.suspend(|| {
-                        if verbose {
-                            eprintln!("Render failed: {}", error);
-                        } else {
-                            eprintln!("Render failed");
+    let progress_handle = match format {
+        OutputFormat::Human => {
+            let pb = (!messages.quiet).then(|| create_progress_bar(total));
+            tokio::spawn(async move {
+                while let Some(progress) = rx.recv().await {
+                    match progress {
+                        Progress::Started { total } => {
+                            if let Some(pb) = &pb {
+                                pb.set_length(total as u64);
+                                pb.set_message("Starting...");
+                            }
                         }
-                    });
-                }
-                Progress::Processing { path, .. } => {
-                    let name = path
-                        .file_name()
-                        .map(|n| n.to_string_lossy().to_string())
-                        .unwrap_or_else(|| path.display().to_string());
-                    pb.set_message(format!("Processing: {}", name));
-                }
-                Progress::FolderComplete { .. } => {
-                    pb.inc(1);
-                }
-                Progress::FolderFailed { path, error, .. } => {
-                    pb.inc(1);
-                    pb.suspend(|| {
-                        if verbose {
-                            eprintln!("Failed {}: {}", path.display(), error);
-                        } else {
-                            eprintln!("Failed {}", path.display());
+                        Progress::Rendering => {
+                            if let Some(pb) = &pb {
+                                pb.set_message("Rendering icons...");
+                            }
+                        }
+                        Progress::RenderFailed { error } => {
+                            let text = if verbose {
+                                format!("Render failed: {}", error)
+                            } else {
+                                "Render failed".to_string()
+                            };
+                            match &pb {
+                                Some(pb) => {
+                                    pb.suspend(|| messages.eprint(MessageKind::Failure, &text))
+                                }
+                                None => messages.eprint(MessageKind::Failure, &text),
+                            }
+                        }
+                        Progress::Processing { path, .. } => {
+                            if let Some(pb) = &pb {
+                                let name = path
+                                    .file_name()
+                                    .map(|n| n.to_string_lossy().to_string())
+                                    .unwrap_or_else(|| path.display().to_string());
+                                pb.set_message(format!("Processing: {}", name));
+                            }
+                        }
+                        Progress::FolderComplete { .. } => {
+                            if let Some(pb) = &pb {
+                                pb.inc(1);
+                            }
+                        }
+                        Progress::FolderFailed { path, error, .. } => {
+                            if let Some(pb) = &pb {
+                                pb.inc(1);
+                            }
+                            let text = if verbose {
+                                format!("Failed {}: {}", path.display(), error)
+                            } else {
+                                format!("Failed {}", path.display())
+                            };
+                            match &pb {
+                                Some(pb) => {
+                                    pb.suspend(|| messages.eprint(MessageKind::Failure, &text))
+                                }
+                                None => messages.eprint(MessageKind::Failure, &text),
+                            }
                         }
-                    });
+                        Progress::Completed { succeeded, failed } => {
+                            if let Some(pb) = &pb {
+                                pb.finish_and_clear();
+                            }
+                            println!("Completed: {} succeeded, {} failed", succeeded, failed);
+                        }
+                    }
                 }
-                Progress::Completed { succeeded, failed } => {
-                    pb.finish_with_message(format!(
-                        "Completed: {} succeeded, {} failed",
-                        succeeded, failed
-                    ));
+            })
+        }
+        OutputFormat::Json => tokio::spawn(async move {
+            let mut index = 0u64;
+            while let Some(progress) = rx.recv().await {
+                if matches!(
+                    progress,
+                    Progress::FolderComplete { .. } | Progress::FolderFailed { .. }
+                ) {
+                    index += 1;
                 }
+                print_json_event(&progress, index, total);
             }
-        }
-    });
+        }),
+    };
 
     // Run customization
     ctx.customize_folders_async(directories, &profile, tx).await;
@@ -321,8 +955,15 @@ async fn customize_folders(directories: Vec<PathBuf>, profile: CustomizationProf
     Ok(())
 }
 
-async fn reset_folders(directories: Vec<PathBuf>, verbose: bool) -> Result<()> {
-    println!("Initializing...");
+async fn reset_folders(
+    directories: Vec<PathBuf>,
+    verbose: bool,
+    format: OutputFormat,
+    messages: MessageOptions,
+) -> Result<()> {
+    if format == OutputFormat::Human {
+        messages.print(MessageKind::Info, "Initializing...");
+    }
 
     let ctx = CustomizationContextBuilder::new()
         .build()
@@ -331,46 +972,74 @@ async fn reset_folders(directories: Vec<PathBuf>, verbose: bool) -> Result<()> {
     let (tx, mut rx) = progress_channel(32);
 
     let total = directories.len() as u64;
-    let pb = create_progress_bar(total);
 
     // Spawn progress handler
-    let progress_handle = tokio::spawn(async move {
-        while let Some(progress) = rx.recv().await {
-            match progress {
-                Progress::Started { total } => {
-                    pb.set_length(total as u64);
-                    pb.set_message("Starting...");
-                }
-                Progress::Processing { path, .. } => {
-                    let name = path
-                        .file_name()
-                        .map(|n| n.to_string_lossy().to_string())
-                        .unwrap_or_else(|| path.display().to_string());
-                    pb.set_message(format!("Resetting: {}", name));
-                }
-                Progress::FolderComplete { .. } => {
-                    pb.inc(1);
-                }
-                Progress::FolderFailed { path, error, .. } => {
-                    pb.inc(1);
-                    pb.suspend(|| {
-                        if verbose {
-                            eprintln!("Failed {}: {}", path.display(), error);
-                        } else {
-                            eprintln!("Failed {}", path.display());
+    let progress_handle = match format {
+        OutputFormat::Human => {
+            let pb = (!messages.quiet).then(|| create_progress_bar(total));
+            tokio::spawn(async move {
+                while let Some(progress) = rx.recv().await {
+                    match progress {
+                        Progress::Started { total } => {
+                            if let Some(pb) = &pb {
+                                pb.set_length(total as u64);
+                                pb.set_message("Starting...");
+                            }
+                        }
+                        Progress::Processing { path, .. } => {
+                            if let Some(pb) = &pb {
+                                let name = path
+                                    .file_name()
+                                    .map(|n| n.to_string_lossy().to_string())
+                                    .unwrap_or_else(|| path.display().to_string());
+                                pb.set_message(format!("Resetting: {}", name));
+                            }
+                        }
+                        Progress::FolderComplete { .. } => {
+                            if let Some(pb) = &pb {
+                                pb.inc(1);
+                            }
+                        }
+                        Progress::FolderFailed { path, error, .. } => {
+                            if let Some(pb) = &pb {
+                                pb.inc(1);
+                            }
+                            let text = if verbose {
+                                format!("Failed {}: {}", path.display(), error)
+                            } else {
+                                format!("Failed {}", path.display())
+                            };
+                            match &pb {
+                                Some(pb) => {
+                                    pb.suspend(|| messages.eprint(MessageKind::Failure, &text))
+                                }
+                                None => messages.eprint(MessageKind::Failure, &text),
+                            }
                         }
-                    });
+                        Progress::Completed { succeeded, failed } => {
+                            if let Some(pb) = &pb {
+                                pb.finish_and_clear();
+                            }
+                            println!("Completed: {} succeeded, {} failed", succeeded, failed);
+                        }
+                        _ => {}
+                    }
                 }
-                Progress::Completed { succeeded, failed } => {
-                    pb.finish_with_message(format!(
-                        "Completed: {} succeeded, {} failed",
-                        succeeded, failed
-                    ));
+            })
+        }
+        OutputFormat::Json => tokio::spawn(async move {
+            let mut index = 0u64;
+            while let Some(progress) = rx.recv().await {
+                if matches!(
+                    progress,
+                    Progress::FolderComplete { .. } | Progress::FolderFailed { .. }
+                ) {
+                    index += 1;
                 }
-                _ => {}
+                print_json_event(&progress, index, total);
             }
-        }
-    });
+        }),
+    };
 
     // Run reset
     ctx.reset_folders_async(directories, tx).await;
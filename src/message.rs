@@ -0,0 +1,82 @@
+//! Human-facing status messages for the progress handlers: an emoji or
+//! ASCII prefix, optionally colored, gated by --no-emoji/--no-color/--quiet.
+
+use std::fmt::Display;
+use std::io::IsTerminal;
+
+#[derive(Copy, Clone)]
+pub enum MessageKind {
+    Success,
+    Failure,
+    Info,
+}
+
+impl MessageKind {
+    fn emoji(self) -> &'static str {
+        match self {
+            MessageKind::Success => "✅",
+            MessageKind::Failure => "❌",
+            MessageKind::Info => "…",
+        }
+    }
+
+    fn ascii_tag(self) -> &'static str {
+        match self {
+            MessageKind::Success => "[ok]",
+            MessageKind::Failure => "[fail]",
+            MessageKind::Info => "[info]",
+        }
+    }
+
+    fn ansi_color(self) -> &'static str {
+        match self {
+            MessageKind::Success => "\u{1b}[32m",
+            MessageKind::Failure => "\u{1b}[31m",
+            MessageKind::Info => "\u{1b}[33m",
+        }
+    }
+}
+
+/// Presentation controls threaded through the progress handlers.
+#[derive(Copy, Clone)]
+pub struct MessageOptions {
+    pub no_emoji: bool,
+    pub no_color: bool,
+    pub quiet: bool,
+}
+
+impl MessageOptions {
+    /// The prefix for a message of this kind, e.g. "✅" or "[ok]", colored
+    /// unless --no-color is set or `is_tty` is false (the caller reports
+    /// whether the stream it's about to write to is actually a terminal).
+    fn prefix(&self, kind: MessageKind, is_tty: bool) -> String {
+        let tag = if self.no_emoji {
+            kind.ascii_tag()
+        } else {
+            kind.emoji()
+        };
+        if self.no_color || !is_tty {
+            tag.to_string()
+        } else {
+            format!("{}{tag}\u{1b}[0m", kind.ansi_color())
+        }
+    }
+
+    /// Print a message to stderr, unless --quiet is set.
+    pub fn eprint(&self, kind: MessageKind, message: impl Display) {
+        if self.quiet {
+            return;
+        }
+        let prefix = self.prefix(kind, std::io::stderr().is_terminal());
+        eprintln!("{prefix} {message}");
+    }
+
+    /// Print a message to stdout, unless --quiet is set.
+    pub fn print(&self, kind: MessageKind, message: impl Display) {
+        if self.quiet {
+            return;
+        }
+        let prefix = self.prefix(kind, std::io::stdout().is_terminal());
+        println!("{prefix} {message}");
+    }
+}
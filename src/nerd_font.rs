@@ -0,0 +1,100 @@
+//! Nerd Font glyph resolution for overlay/decal sources.
+//!
+//! Recognizes a small, hand-picked set of Nerd Font icon names (e.g.
+//! `nf-dev-rust`; see `GLYPH_NAMES`) and literal Private Use Area
+//! codepoints, and shapes the matched glyph into an SVG outline sized for
+//! the overlay/decal viewBox via `SerializableSvgSource::from_glyph`.
+
+use anyhow::{bail, Context, Result};
+use folco_core::SerializableSvgSource;
+
+/// A hand-picked subset of the Nerd Fonts name -> codepoint table, covering
+/// common dev-icon use (language/VCS/container icons). This is intentionally
+/// scoped down from the full upstream `glyphnames.json` (several thousand
+/// entries) rather than generated; any name not listed here falls through to
+/// the "unknown glyph" error below, which suggests the closest known names.
+/// Widening this to the full generated table is tracked as follow-up work
+/// (likely via a build script pulling `glyphnames.json` at build time).
+const GLYPH_NAMES: &[(&str, char)] = &[
+    ("nf-dev-rust", '\u{e7a8}'),
+    ("nf-dev-git", '\u{e702}'),
+    ("nf-dev-git_branch", '\u{f418}'),
+    ("nf-dev-javascript", '\u{e74e}'),
+    ("nf-dev-python", '\u{e73c}'),
+    ("nf-fa-folder", '\u{f07b}'),
+    ("nf-fa-folder_open", '\u{f07c}'),
+    ("nf-md-docker", '\u{f308}'),
+    ("nf-oct-mark_github", '\u{f408}'),
+];
+
+/// Private Use Area ranges Nerd Fonts (and the wider Unicode PUA) occupy.
+fn is_pua_codepoint(c: char) -> bool {
+    matches!(c as u32, 0xE000..=0xF8FF | 0xF0000..=0xFFFFD | 0x100000..=0x10FFFD)
+}
+
+/// Try to resolve `input` as a Nerd Font glyph reference: either a known
+/// icon name or a single literal PUA codepoint. Returns `Ok(None)` when
+/// `input` plainly isn't a glyph reference, so callers can fall through to
+/// their own fallback (e.g. treating it as an emoji name).
+pub fn resolve_nerd_font_source(input: &str) -> Result<Option<SerializableSvgSource>> {
+    if let Some((_, codepoint)) = GLYPH_NAMES.iter().find(|(name, _)| *name == input) {
+        return Ok(Some(shape_glyph(*codepoint)?));
+    }
+
+    let mut chars = input.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if is_pua_codepoint(c) {
+            return Ok(Some(shape_glyph(c)?));
+        }
+    }
+
+    if input.starts_with("nf-") {
+        bail!(
+            "Unknown Nerd Font glyph {:?}. Did you mean: {}?",
+            input,
+            closest_names(input).join(", ")
+        );
+    }
+
+    Ok(None)
+}
+
+fn shape_glyph(codepoint: char) -> Result<SerializableSvgSource> {
+    SerializableSvgSource::from_glyph(codepoint).with_context(|| {
+        format!(
+            "No glyph for U+{:04X} in the bundled Nerd Font",
+            codepoint as u32
+        )
+    })
+}
+
+/// The three known glyph names closest to `input`, for error messages.
+fn closest_names(input: &str) -> Vec<&'static str> {
+    let mut scored: Vec<(usize, &'static str)> = GLYPH_NAMES
+        .iter()
+        .map(|(name, _)| (levenshtein(input, name), *name))
+        .collect();
+    scored.sort_by_key(|(dist, _)| *dist);
+    scored.into_iter().take(3).map(|(_, name)| name).collect()
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut dp = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in dp.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=b.len() {
+        dp[0][j] = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            dp[i][j] = (dp[i - 1][j] + 1)
+                .min(dp[i][j - 1] + 1)
+                .min(dp[i - 1][j - 1] + cost);
+        }
+    }
+    dp[a.len()][b.len()]
+}
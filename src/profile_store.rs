@@ -0,0 +1,141 @@
+//! Named profile library: persist `CustomizationProfile` JSON under the
+//! platform config directory (e.g. `~/.config/folco/profiles/` on Linux) so
+//! users can define a look once ("work", "archive", "media") and apply it
+//! by name across sessions instead of re-typing flags or pasting JSON.
+
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use folco_core::CustomizationProfile;
+
+fn profiles_dir() -> Result<PathBuf> {
+    let config_dir =
+        dirs::config_dir().context("Could not determine the platform config directory")?;
+    Ok(config_dir.join("folco").join("profiles"))
+}
+
+/// Reject anything that isn't a plain filename: no separators, no `.`/`..`,
+/// no absolute paths. Profile names flow straight into a file path, so a
+/// name like `../../etc/passwd` or `/etc/passwd` must not be allowed to
+/// escape the profiles directory. Checked against the raw string (not just
+/// `Path`'s normalized components), since e.g. `Path::new("foo/")` silently
+/// drops the trailing separator and would otherwise pass as `"foo"`.
+fn validate_name(name: &str) -> Result<()> {
+    if name.contains(std::path::is_separator) {
+        bail!("Invalid profile name {name:?}: must not contain a path separator");
+    }
+    let mut components = Path::new(name).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(_)), None) => Ok(()),
+        _ => bail!("Invalid profile name {name:?}: must be a single path component"),
+    }
+}
+
+fn profile_path(name: &str) -> Result<PathBuf> {
+    validate_name(name)?;
+    Ok(profiles_dir()?.join(format!("{name}.json")))
+}
+
+/// Save `profile` under `name`, creating the profiles directory if needed.
+pub fn save(name: &str, profile: &CustomizationProfile) -> Result<()> {
+    let dir = profiles_dir()?;
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create profile directory: {}", dir.display()))?;
+
+    let json = profile
+        .to_json()
+        .context("Failed to serialize CustomizationProfile")?;
+    let path = profile_path(name)?;
+    std::fs::write(&path, json)
+        .with_context(|| format!("Failed to write profile: {}", path.display()))
+}
+
+/// Load the profile saved under `name`.
+pub fn load(name: &str) -> Result<CustomizationProfile> {
+    let json = show(name)?;
+    CustomizationProfile::from_json(&json).context("Failed to parse saved profile JSON")
+}
+
+/// Names of all saved profiles, sorted.
+pub fn list() -> Result<Vec<String>> {
+    let dir = profiles_dir()?;
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut names: Vec<String> = std::fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read profile directory: {}", dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                path.file_stem().map(|s| s.to_string_lossy().to_string())
+            } else {
+                None
+            }
+        })
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+/// The raw JSON for the profile saved under `name`.
+pub fn show(name: &str) -> Result<String> {
+    let path = profile_path(name)?;
+    std::fs::read_to_string(&path).with_context(|| {
+        format!(
+            "No saved profile named {:?} (looked in {})",
+            name,
+            path.display()
+        )
+    })
+}
+
+/// Delete the profile saved under `name`.
+pub fn remove(name: &str) -> Result<()> {
+    let path = profile_path(name)?;
+    std::fs::remove_file(&path).with_context(|| {
+        format!(
+            "No saved profile named {:?} (looked in {})",
+            name,
+            path.display()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_name;
+
+    #[test]
+    fn accepts_plain_names() {
+        assert!(validate_name("work").is_ok());
+        assert!(validate_name("my-profile_2").is_ok());
+    }
+
+    #[test]
+    fn rejects_parent_traversal() {
+        assert!(validate_name("../etc/passwd").is_err());
+        assert!(validate_name("..").is_err());
+    }
+
+    #[test]
+    fn rejects_absolute_paths() {
+        assert!(validate_name("/etc/passwd").is_err());
+    }
+
+    #[test]
+    fn rejects_embedded_separators() {
+        assert!(validate_name("foo/bar").is_err());
+    }
+
+    #[test]
+    fn rejects_trailing_separator() {
+        assert!(validate_name("foo/").is_err());
+    }
+
+    #[test]
+    fn rejects_current_dir() {
+        assert!(validate_name(".").is_err());
+    }
+}
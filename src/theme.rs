@@ -0,0 +1,244 @@
+//! Icon themes: map folder names to customization profiles via a TOML file.
+//!
+//! A theme is an ordered list of `[[rule]]` entries plus an optional
+//! `[default]` fallback, e.g.:
+//!
+//! ```toml
+//! [default]
+//! color = "gray"
+//!
+//! [[rule]]
+//! match = "node_modules"
+//! overlay = "package"
+//!
+//! [[rule]]
+//! match_glob = "*.git"
+//! color = "gray"
+//! ```
+//!
+//! Rules are evaluated in file order; when several match the same
+//! directory their fields cascade, with a later rule's fields overriding
+//! only the fields it sets, mirroring how editor icon configs layer a
+//! default flavor with overrides.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use folco_core::{
+    color::FolderColor, CustomizationProfile, DecalSettings, OverlaySettings, SerializablePosition,
+};
+
+use crate::glob::glob_match;
+use crate::{resolve_decal_source, resolve_overlay_source};
+
+#[derive(Debug, Deserialize)]
+pub struct Theme {
+    #[serde(default)]
+    default: Option<ThemeRule>,
+    #[serde(rename = "rule", default)]
+    rules: Vec<ThemeRule>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ThemeRule {
+    /// Exact folder name to match, e.g. "node_modules"
+    #[serde(rename = "match")]
+    match_name: Option<String>,
+    /// Glob matched against the folder name, e.g. "*.git"
+    match_glob: Option<String>,
+
+    color: Option<FolderColor>,
+    decal: Option<String>,
+    decal_scale: Option<f32>,
+    overlay: Option<String>,
+    overlay_position: Option<OverlayPositionName>,
+    overlay_scale: Option<f32>,
+}
+
+fn default_scale() -> f32 {
+    0.70
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum OverlayPositionName {
+    BottomLeft,
+    #[default]
+    BottomRight,
+    TopLeft,
+    TopRight,
+    Center,
+}
+
+impl From<OverlayPositionName> for SerializablePosition {
+    fn from(pos: OverlayPositionName) -> Self {
+        match pos {
+            OverlayPositionName::BottomLeft => SerializablePosition::BottomLeft,
+            OverlayPositionName::BottomRight => SerializablePosition::BottomRight,
+            OverlayPositionName::TopLeft => SerializablePosition::TopLeft,
+            OverlayPositionName::TopRight => SerializablePosition::TopRight,
+            OverlayPositionName::Center => SerializablePosition::Center,
+        }
+    }
+}
+
+impl Theme {
+    /// Load and parse a theme file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read theme file: {}", path.display()))?;
+        toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse theme file: {}", path.display()))
+    }
+
+    /// Resolve the profile that should apply to a directory with this
+    /// basename, or `None` if there is no default rule and nothing matches.
+    ///
+    /// The default rule (if any) forms the base layer; every matching rule
+    /// then cascades on top of it in file order, each overriding only the
+    /// fields it sets, so an earlier rule's color can survive under a later
+    /// rule that only adds an overlay.
+    pub fn resolve(&self, dir_name: &str) -> Result<Option<CustomizationProfile>> {
+        let layers = self
+            .default
+            .iter()
+            .chain(self.rules.iter().filter(|rule| rule.matches(dir_name)));
+
+        let Some(effective) = EffectiveRule::merge(layers) else {
+            return Ok(None);
+        };
+
+        let mut profile = CustomizationProfile::new();
+
+        if let Some(color) = effective.color {
+            profile = profile.with_hsl_mutation(color.to_hsl_mutation_settings());
+        }
+
+        if let Some(decal) = effective.decal {
+            profile = profile.with_decal(DecalSettings {
+                source: resolve_decal_source(&decal)?,
+                scale: effective.decal_scale.unwrap_or_else(default_scale),
+                enabled: true,
+            });
+        }
+
+        if let Some(overlay) = effective.overlay {
+            profile = profile.with_overlay(OverlaySettings {
+                source: resolve_overlay_source(&overlay)?,
+                position: effective.overlay_position.unwrap_or_default().into(),
+                scale: effective.overlay_scale.unwrap_or_else(default_scale),
+                enabled: true,
+            });
+        }
+
+        Ok(Some(profile))
+    }
+}
+
+impl ThemeRule {
+    fn matches(&self, dir_name: &str) -> bool {
+        if self.match_name.as_deref() == Some(dir_name) {
+            return true;
+        }
+        if let Some(glob) = &self.match_glob {
+            return glob_match(glob, dir_name);
+        }
+        false
+    }
+}
+
+/// The result of cascading a sequence of `ThemeRule` layers: each field is
+/// taken from the last layer that set it, independent of its sibling
+/// fields, so a later rule re-setting `decal` without repeating
+/// `decal_scale` doesn't reset the scale an earlier layer set.
+#[derive(Default, Debug)]
+struct EffectiveRule {
+    color: Option<FolderColor>,
+    decal: Option<String>,
+    decal_scale: Option<f32>,
+    overlay: Option<String>,
+    overlay_position: Option<OverlayPositionName>,
+    overlay_scale: Option<f32>,
+}
+
+impl EffectiveRule {
+    /// Merge `layers` in order, or `None` if `layers` is empty.
+    fn merge<'a>(layers: impl Iterator<Item = &'a ThemeRule>) -> Option<Self> {
+        let mut merged = EffectiveRule::default();
+        let mut any_layer = false;
+
+        for rule in layers {
+            any_layer = true;
+            if let Some(c) = rule.color {
+                merged.color = Some(c);
+            }
+            if let Some(d) = &rule.decal {
+                merged.decal = Some(d.clone());
+            }
+            if let Some(s) = rule.decal_scale {
+                merged.decal_scale = Some(s);
+            }
+            if let Some(o) = &rule.overlay {
+                merged.overlay = Some(o.clone());
+            }
+            if let Some(p) = &rule.overlay_position {
+                merged.overlay_position = Some(p.clone());
+            }
+            if let Some(s) = rule.overlay_scale {
+                merged.overlay_scale = Some(s);
+            }
+        }
+
+        any_layer.then_some(merged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(match_name: Option<&str>, decal: Option<&str>, decal_scale: Option<f32>) -> ThemeRule {
+        ThemeRule {
+            match_name: match_name.map(str::to_string),
+            match_glob: None,
+            color: None,
+            decal: decal.map(str::to_string),
+            decal_scale,
+            overlay: None,
+            overlay_position: None,
+            overlay_scale: None,
+        }
+    }
+
+    #[test]
+    fn merge_keeps_sibling_field_when_later_rule_omits_it() {
+        let rules = vec![
+            rule(None, Some("a.svg"), Some(0.9)),
+            rule(Some("src"), Some("b.svg"), None),
+        ];
+
+        let merged = EffectiveRule::merge(rules.iter()).expect("at least one layer");
+
+        assert_eq!(merged.decal.as_deref(), Some("b.svg"));
+        assert_eq!(merged.decal_scale, Some(0.9));
+    }
+
+    #[test]
+    fn merge_overrides_sibling_field_when_later_rule_sets_it() {
+        let rules = vec![
+            rule(None, Some("a.svg"), Some(0.9)),
+            rule(Some("src"), Some("b.svg"), Some(0.3)),
+        ];
+
+        let merged = EffectiveRule::merge(rules.iter()).expect("at least one layer");
+
+        assert_eq!(merged.decal_scale, Some(0.3));
+    }
+
+    #[test]
+    fn merge_of_no_layers_is_none() {
+        assert!(EffectiveRule::merge(std::iter::empty()).is_none());
+    }
+}